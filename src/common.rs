@@ -1,12 +1,119 @@
-use std::{cell::RefCell, rc::Rc};
-
-pub(crate) type Link<K> = Option<NodeRef<K>>;
-
-pub(crate) type NodeRef<K> = Rc<RefCell<Node<K>>>;
-
+/// A node in an [`IntrusiveList`], stored inline in its backing slab.
 #[derive(Debug)]
 pub(crate) struct Node<K> {
     pub(crate) key: K,
-    pub(crate) next: Link<K>,
-    pub(crate) prev: Link<K>,
+    pub(crate) next: Option<usize>,
+    pub(crate) prev: Option<usize>,
+}
+
+/// An intrusive doubly linked list of [`Node`]s backed by a single `Vec`
+/// (a slab), with freed slots tracked on a free-list for reuse.
+///
+/// `next`/`prev` links are slab indices rather than pointers, so unlinking a
+/// node is an O(1) array write instead of a pointer-chasing, heap-allocated
+/// operation.
+#[derive(Debug)]
+pub(crate) struct IntrusiveList<K> {
+    slab: Vec<Node<K>>,
+    free: Vec<usize>,
+    pub(crate) head: Option<usize>,
+    pub(crate) tail: Option<usize>,
+}
+
+impl<K> IntrusiveList<K> {
+    /// Creates an empty list with slab storage pre-allocated for `capacity` nodes.
+    #[inline]
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self { slab: Vec::with_capacity(capacity), free: Vec::new(), head: None, tail: None }
+    }
+
+    /// Returns a reference to the key stored at `index`.
+    #[inline]
+    pub(crate) fn key(&self, index: usize) -> &K {
+        &self.slab[index].key
+    }
+
+    /// Returns the node stored at `index`.
+    #[inline]
+    pub(crate) fn node(&self, index: usize) -> &Node<K> {
+        &self.slab[index]
+    }
+
+    /// Inserts `key` as a new node at the tail of the list, reusing a freed
+    /// slab slot if one is available, and returns its slab index.
+    #[inline]
+    pub(crate) fn push_back(&mut self, key: K) -> usize {
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.slab[index] = Node { key, next: None, prev: self.tail };
+                index
+            }
+            None => {
+                self.slab.push(Node { key, next: None, prev: self.tail });
+                self.slab.len() - 1
+            }
+        };
+
+        if let Some(tail) = self.tail {
+            self.slab[tail].next = Some(index);
+        }
+        self.tail = Some(index);
+        if self.head.is_none() {
+            self.head = Some(index);
+        }
+
+        index
+    }
+
+    /// Unlinks the node at `index` from the list, patching `head`/`tail` and
+    /// neighboring links, and pushes the freed slot onto the free-list for
+    /// reuse. The caller is responsible for removing the corresponding entry
+    /// from whatever hash structure maps keys to slab indices.
+    #[inline]
+    pub(crate) fn unlink(&mut self, index: usize) {
+        let prev = self.slab[index].prev;
+        let next = self.slab[index].next;
+
+        match prev {
+            Some(prev) => self.slab[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.slab[next].prev = prev,
+            None => self.tail = prev,
+        }
+
+        self.free.push(index);
+    }
+
+    /// Unlinks the node at `index` and relinks it at the tail, i.e. the
+    /// most-recently-used end, patching `head`/`tail` if `index` was one of
+    /// the endpoints. This is a no-op if `index` is already the tail.
+    #[inline]
+    pub(crate) fn move_to_back(&mut self, index: usize) {
+        if self.tail == Some(index) {
+            return;
+        }
+
+        let prev = self.slab[index].prev;
+        let next = self.slab[index].next;
+
+        match prev {
+            Some(prev) => self.slab[prev].next = next,
+            None => self.head = next,
+        }
+        if let Some(next) = next {
+            self.slab[next].prev = prev;
+        }
+
+        self.slab[index].prev = self.tail;
+        self.slab[index].next = None;
+        if let Some(tail) = self.tail {
+            self.slab[tail].next = Some(index);
+        }
+        self.tail = Some(index);
+        if self.head.is_none() {
+            self.head = Some(index);
+        }
+    }
 }
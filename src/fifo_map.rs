@@ -1,7 +1,14 @@
-use hashbrown::{hash_map::DefaultHashBuilder, HashMap};
-use std::{cell::RefCell, hash::Hash, num::NonZeroUsize, rc::Rc};
+use hashbrown::{hash_map, hash_map::DefaultHashBuilder, HashMap};
+use std::{
+    hash::{BuildHasher, Hash},
+    num::NonZeroUsize,
+};
 
-use crate::common::{Link, Node, NodeRef};
+use crate::common::IntrusiveList;
+
+/// The capacity used by [`FIFOMap::with_hasher`] when no explicit capacity
+/// is given.
+const DEFAULT_CAPACITY: usize = 16;
 
 /// A First-In-First-Out (FIFO) map.
 ///
@@ -15,7 +22,7 @@ use crate::common::{Link, Node, NodeRef};
 /// # Example
 ///
 /// ```rust
-/// use fifo_map::FIFOMap;
+/// use hashmore::FIFOMap;
 ///
 /// let mut map = FIFOMap::with_capacity(3);
 ///
@@ -35,9 +42,8 @@ use crate::common::{Link, Node, NodeRef};
 /// ```
 #[derive(Debug)]
 pub struct FIFOMap<K, V, S = DefaultHashBuilder> {
-    map: HashMap<K, (V, NodeRef<K>), S>,
-    head: Link<K>,
-    tail: Link<K>,
+    map: HashMap<K, (V, usize), S>,
+    list: IntrusiveList<K>,
     cap: NonZeroUsize,
 }
 
@@ -54,55 +60,79 @@ where
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         let cap = NonZeroUsize::new(capacity).expect("FIFOMap capacity must be non-zero");
-        Self { map: HashMap::with_capacity(capacity), head: None, tail: None, cap }
+        Self {
+            map: HashMap::with_capacity(capacity),
+            list: IntrusiveList::with_capacity(capacity),
+            cap,
+        }
     }
 }
 
-impl<K, V> FIFOMap<K, V>
+impl<K, V, S> FIFOMap<K, V, S> {
+    /// Creates a new FIFO map which will use the given hash builder, with a
+    /// default capacity of 16 entries.
+    ///
+    /// Use [`with_capacity_and_hasher`](Self::with_capacity_and_hasher) to
+    /// choose an explicit capacity.
+    #[inline]
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(DEFAULT_CAPACITY, hasher)
+    }
+
+    /// Creates a new FIFO map with the given capacity which will use the
+    /// given hash builder.
+    /// The capacity must be greater than zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero.
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        let cap = NonZeroUsize::new(capacity).expect("FIFOMap capacity must be non-zero");
+        Self {
+            map: HashMap::with_capacity_and_hasher(capacity, hasher),
+            list: IntrusiveList::with_capacity(capacity),
+            cap,
+        }
+    }
+
+    /// Returns a reference to the map's [`BuildHasher`].
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+}
+
+impl<K, V, S> FIFOMap<K, V, S>
 where
     K: Eq + Hash + Clone,
+    S: BuildHasher,
 {
     /// Inserts a new key-value pair into the map.
     /// - If the map is at capacity, the oldest entry will be removed.
-    /// - If the key is already in the map, the value will be updated.
+    /// - If the key is already in the map, the value will be updated in
+    ///   place and FIFO order is left undisturbed.
     #[inline]
     pub fn insert(&mut self, key: K, value: V) {
-        if self.map.len() == self.cap.get() {
-            self.remove_first();
-        }
-
-        let new_node = Node { key: key.clone(), next: None, prev: self.tail.clone() };
-        let new_node_ref = Rc::new(RefCell::new(new_node));
-
-        if let Some(tail) = self.tail.take() {
-            tail.borrow_mut().next = Some(new_node_ref.clone());
+        if let Some((v, _)) = self.map.get_mut(&key) {
+            *v = value;
+            return;
         }
-        self.tail = Some(new_node_ref.clone());
 
-        if self.head.is_none() {
-            self.head = Some(new_node_ref.clone());
+        if self.map.len() == self.cap.get() {
+            self.remove_first();
         }
 
-        self.map.insert(key, (value, new_node_ref));
+        let index = self.list.push_back(key.clone());
+        self.map.insert(key, (value, index));
     }
 
     /// Removes a key-value pair from the map and returns the value.
     /// If the key is not in the map, `None` is returned.
     #[inline]
     pub fn remove(&mut self, key: &K) -> Option<V> {
-        self.map.remove(key).map(|(v, node)| {
-            if let Some(prev) = node.borrow().prev.clone() {
-                prev.borrow_mut().next.clone_from(&node.borrow().next)
-            } else {
-                self.head.clone_from(&node.borrow().next)
-            }
-
-            if let Some(next) = node.borrow().next.clone() {
-                next.borrow_mut().prev.clone_from(&node.borrow().prev)
-            } else {
-                self.tail.clone_from(&node.borrow().prev);
-            }
-
+        self.map.remove(key).map(|(v, index)| {
+            self.list.unlink(index);
             v
         })
     }
@@ -128,22 +158,28 @@ where
     /// An iterator visiting all keys in insertion order.
     /// The keys are returned by reference.
     #[inline]
-    pub fn keys(&self) -> impl Iterator<Item = &K> {
-        self.map.keys()
+    pub const fn keys(&self) -> Keys<'_, K, V, S> {
+        Keys { inner: self.iter() }
     }
 
     /// An iterator visiting all values in insertion order.
     /// The values are returned by reference.
     #[inline]
-    pub fn values(&self) -> impl Iterator<Item = &V> {
-        self.map.values().map(|(v, _)| v)
+    pub const fn values(&self) -> Values<'_, K, V, S> {
+        Values { inner: self.iter() }
     }
 
     /// An iterator visiting all key-value pairs in insertion order.
     /// The key-value pairs are returned by reference.
+    ///
+    /// This walks the intrusive linked list from the oldest entry (`head`) to
+    /// the newest (`tail`), so the order always matches insertion order,
+    /// regardless of the underlying hashmap's iteration order. The iterator
+    /// is also [`DoubleEndedIterator`], so `.rev()` walks from `tail` to
+    /// `head`.
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
-        self.map.iter().map(|(k, (v, _))| (k, v))
+    pub const fn iter(&self) -> Iter<'_, K, V, S> {
+        Iter { map: &self.map, list: &self.list, front: self.list.head, back: self.list.tail }
     }
 
     /// Returns a reference to the value corresponding to the key.
@@ -162,26 +198,388 @@ where
         self.map.contains_key(key)
     }
 
+    /// Returns a mutable reference to the value corresponding to the key,
+    /// without changing the entry's position in the FIFO order.
+    /// If the key is not in the map, `None` is returned.
+    #[inline]
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.map.get_mut(key).map(|(v, _)| v)
+    }
+
+    /// Retains only the entries for which `f` returns `true`, visiting them
+    /// in FIFO order.
+    ///
+    /// Entries for which `f` returns `false` are unlinked from the list and
+    /// removed from the map, exactly as [`remove`](Self::remove) does.
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut current = self.list.head;
+        while let Some(index) = current {
+            current = self.list.node(index).next;
+
+            let key = self.list.key(index).clone();
+            let keep = self.map.get_mut(&key).is_some_and(|(value, _)| f(&key, value));
+            if !keep {
+                self.list.unlink(index);
+                self.map.remove(&key);
+            }
+        }
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation.
+    ///
+    /// Inserting through the returned [`Entry::or_insert`] (or its
+    /// variants) respects the same capacity and eviction rules as
+    /// [`insert`](Self::insert): if the map is full and the key is not
+    /// already present, the oldest entry is evicted first. Looking up or
+    /// modifying an already-occupied entry does not disturb FIFO order.
+    #[inline]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        if self.map.contains_key(&key) {
+            let (value, _) = self.map.get_mut(&key).expect("key just confirmed present");
+            Entry::Occupied(OccupiedEntry { value, key })
+        } else {
+            Entry::Vacant(VacantEntry { map: &mut self.map, list: &mut self.list, cap: self.cap, key })
+        }
+    }
+
     /// Removes the oldest entry from the map.
     /// If the map is empty, this is a no-op.
     fn remove_first(&mut self) {
-        if let Some(head) = self.head.take() {
-            if let Some(next) = head.borrow_mut().next.take() {
-                next.borrow_mut().prev = None;
-                self.head = Some(next);
-            } else {
-                self.tail.take();
+        if let Some(head) = self.list.head {
+            let key = self.list.key(head).clone();
+            self.list.unlink(head);
+            self.map.remove(&key);
+        }
+    }
+}
+
+/// An iterator over the key-value pairs of a [`FIFOMap`], in insertion order.
+///
+/// This struct is created by the [`iter`](FIFOMap::iter) method. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct Iter<'a, K, V, S = DefaultHashBuilder> {
+    map: &'a HashMap<K, (V, usize), S>,
+    list: &'a IntrusiveList<K>,
+    front: Option<usize>,
+    back: Option<usize>,
+}
+
+impl<'a, K, V, S> Iterator for Iter<'a, K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.front.take()?;
+        if self.back == Some(index) {
+            self.back = None;
+        } else {
+            self.front = self.list.node(index).next;
+        }
+
+        let key = self.list.key(index).clone();
+        self.map.get_key_value(&key).map(|(k, (v, _))| (k, v))
+    }
+}
+
+impl<'a, K, V, S> DoubleEndedIterator for Iter<'a, K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.back.take()?;
+        if self.front == Some(index) {
+            self.front = None;
+        } else {
+            self.back = self.list.node(index).prev;
+        }
+
+        let key = self.list.key(index).clone();
+        self.map.get_key_value(&key).map(|(k, (v, _))| (k, v))
+    }
+}
+
+/// An iterator visiting all keys of a [`FIFOMap`], in insertion order.
+///
+/// This struct is created by the [`keys`](FIFOMap::keys) method. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct Keys<'a, K, V, S = DefaultHashBuilder> {
+    inner: Iter<'a, K, V, S>,
+}
+
+impl<'a, K, V, S> Iterator for Keys<'a, K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(k, _)| k)
+    }
+}
+
+impl<'a, K, V, S> DoubleEndedIterator for Keys<'a, K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(k, _)| k)
+    }
+}
+
+/// An iterator visiting all values of a [`FIFOMap`], in insertion order.
+///
+/// This struct is created by the [`values`](FIFOMap::values) method. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct Values<'a, K, V, S = DefaultHashBuilder> {
+    inner: Iter<'a, K, V, S>,
+}
+
+impl<'a, K, V, S> Iterator for Values<'a, K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K, V, S> DoubleEndedIterator for Values<'a, K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+/// A view into a single entry in a [`FIFOMap`], which may be either vacant
+/// or occupied.
+///
+/// This struct is created by the [`entry`](FIFOMap::entry) method.
+#[derive(Debug)]
+pub enum Entry<'a, K, V, S = DefaultHashBuilder> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V>),
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    /// Ensures a value is in the entry by inserting `default` if empty, and
+    /// returns a mutable reference to the value in the entry.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `default`
+    /// if empty, and returns a mutable reference to the value in the entry.
+    #[inline]
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `default`,
+    /// called with the entry's key, if empty. Returns a mutable reference to
+    /// the value in the entry.
+    #[inline]
+    pub fn or_insert_with_key<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce(&K) -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map. This is a no-op on a vacant entry.
+    #[inline]
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    /// Returns a reference to this entry's key.
+    #[inline]
+    pub const fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+}
+
+/// A view into an occupied entry in a [`FIFOMap`].
+///
+/// This struct is part of the [`Entry`] enum. It borrows the value found by
+/// [`entry`](FIFOMap::entry), so `get`, `get_mut` and `into_mut` do not
+/// re-hash the key on every call.
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, K, V> {
+    value: &'a mut V,
+    key: K,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Returns a reference to this entry's key.
+    #[inline]
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns a reference to the entry's value.
+    #[inline]
+    pub const fn get(&self) -> &V {
+        self.value
+    }
+
+    /// Returns a mutable reference to the entry's value.
+    #[inline]
+    pub const fn get_mut(&mut self) -> &mut V {
+        self.value
+    }
+
+    /// Converts the entry into a mutable reference to its value, tied to the
+    /// lifetime of the map rather than the entry itself.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        self.value
+    }
+
+    /// Replaces the entry's value with `value`, returning the old value.
+    #[inline]
+    pub const fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+}
+
+/// A view into a vacant entry in a [`FIFOMap`].
+///
+/// This struct is part of the [`Entry`] enum.
+#[derive(Debug)]
+pub struct VacantEntry<'a, K, V, S = DefaultHashBuilder> {
+    map: &'a mut HashMap<K, (V, usize), S>,
+    list: &'a mut IntrusiveList<K>,
+    cap: NonZeroUsize,
+    key: K,
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    /// Returns a reference to this entry's key.
+    #[inline]
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Inserts the entry's key and `value` into the map, evicting the oldest
+    /// entry first if the map is at capacity, and returns a mutable
+    /// reference to the newly inserted value.
+    ///
+    /// This reuses the absence already established by
+    /// [`entry`](FIFOMap::entry) and performs a single additional hash
+    /// lookup to place the new entry, rather than re-checking and
+    /// re-fetching the key.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        if self.map.len() == self.cap.get() {
+            if let Some(head) = self.list.head {
+                let evicted_key = self.list.key(head).clone();
+                self.list.unlink(head);
+                self.map.remove(&evicted_key);
+            }
+        }
+
+        let index = self.list.push_back(self.key.clone());
+        match self.map.entry(self.key) {
+            hash_map::Entry::Vacant(inner) => &mut inner.insert((value, index)).0,
+            hash_map::Entry::Occupied(_) => {
+                unreachable!("VacantEntry's key must still be absent from the map")
             }
-            let key = &head.borrow().key;
-            self.map.remove(key);
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use hashbrown::hash_map::DefaultHashBuilder;
+
     use crate::fifo_map::FIFOMap;
 
+    #[test]
+    fn test_fifo_map_with_hasher() {
+        let mut map = FIFOMap::with_hasher(DefaultHashBuilder::default());
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        assert_eq!(map.get(&"a"), Some(&1));
+        assert_eq!(map.get(&"b"), Some(&2));
+    }
+
+    #[test]
+    fn test_fifo_map_with_capacity_and_hasher() {
+        let mut map = FIFOMap::with_capacity_and_hasher(2, DefaultHashBuilder::default());
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        assert_eq!(map.get(&"a"), None);
+        assert_eq!(map.get(&"b"), Some(&2));
+        assert_eq!(map.get(&"c"), Some(&3));
+    }
+
     #[test]
     fn test_fifo_map_reach_cap() {
         let mut map = FIFOMap::with_capacity(3);
@@ -227,6 +625,27 @@ mod tests {
         assert_eq!(map.get(&"f"), Some(&6));
     }
 
+    #[test]
+    fn test_fifo_map_insert_existing_key_does_not_duplicate_list_node() {
+        let mut map = FIFOMap::with_capacity(5);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+        map.insert("a", 100);
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.iter().count(), 3);
+        assert_eq!(map.get(&"a"), Some(&100));
+
+        map.insert("d", 4);
+        map.insert("e", 5);
+        map.insert("f", 6);
+
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.iter().count(), 5);
+    }
+
     #[test]
     #[should_panic]
     fn test_fifo_map_zero_capacity() {
@@ -249,4 +668,152 @@ mod tests {
         assert_eq!(map.get(&"b"), None);
         assert_eq!(map.get(&"c"), None);
     }
+
+    #[test]
+    fn test_fifo_map_remove_then_insert_reuses_slab_slot() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        map.remove(&"b");
+        map.insert("d", 4);
+
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&"a", &1), (&"c", &3), (&"d", &4)]);
+    }
+
+    #[test]
+    fn test_fifo_map_iter_order() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"a", &"b", &"c"]);
+        assert_eq!(map.values().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&"a", &1), (&"b", &2), (&"c", &3)]);
+    }
+
+    #[test]
+    fn test_fifo_map_iter_rev() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        assert_eq!(map.iter().rev().collect::<Vec<_>>(), vec![(&"c", &3), (&"b", &2), (&"a", &1)]);
+    }
+
+    #[test]
+    fn test_fifo_map_entry_or_insert_with_vacant() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.entry("a").or_insert_with(Vec::new).push(1);
+        map.entry("a").or_insert_with(Vec::new).push(2);
+
+        assert_eq!(map.get(&"a"), Some(&vec![1, 2]));
+    }
+
+    #[test]
+    fn test_fifo_map_entry_or_insert_occupied_keeps_fifo_order() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        *map.entry("a").or_insert(0) += 10;
+
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"a", &"b", &"c"]);
+        assert_eq!(map.get(&"a"), Some(&11));
+    }
+
+    #[test]
+    fn test_fifo_map_entry_vacant_evicts_oldest() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        map.entry("d").or_insert(4);
+
+        assert_eq!(map.get(&"a"), None);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"b", &"c", &"d"]);
+    }
+
+    #[test]
+    fn test_fifo_map_entry_vacant_drop_without_insert_does_not_evict() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        // Merely acquiring a vacant entry must not commit anything to the
+        // map until `insert`/`or_insert` is actually called.
+        let _ = map.entry("d");
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"a", &"b", &"c"]);
+    }
+
+    #[test]
+    fn test_fifo_map_get_mut() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+        *map.get_mut(&"a").unwrap() += 10;
+
+        assert_eq!(map.get(&"a"), Some(&11));
+        assert_eq!(map.get_mut(&"b"), None);
+    }
+
+    #[test]
+    fn test_fifo_map_retain() {
+        let mut map = FIFOMap::with_capacity(4);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+        map.insert("d", 4);
+
+        map.retain(|_, v| *v % 2 == 0);
+
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"b", &"d"]);
+        assert_eq!(map.get(&"a"), None);
+        assert_eq!(map.get(&"b"), Some(&2));
+        assert_eq!(map.get(&"c"), None);
+        assert_eq!(map.get(&"d"), Some(&4));
+    }
+
+    #[test]
+    fn test_fifo_map_retain_then_insert_reuses_slab_slot() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        map.retain(|k, _| *k != "b");
+        map.insert("d", 4);
+
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&"a", &1), (&"c", &3), (&"d", &4)]);
+    }
+
+    #[test]
+    fn test_fifo_map_entry_and_modify() {
+        let mut map = FIFOMap::with_capacity(3);
+
+        map.insert("a", 1);
+
+        map.entry("a").and_modify(|v| *v += 1).or_insert(100);
+        map.entry("b").and_modify(|v| *v += 1).or_insert(100);
+
+        assert_eq!(map.get(&"a"), Some(&2));
+        assert_eq!(map.get(&"b"), Some(&100));
+    }
 }
@@ -1,13 +1,15 @@
 use std::{
-    cell::RefCell,
     hash::{BuildHasher, Hash},
     num::NonZeroUsize,
-    rc::Rc,
 };
 
-use hashbrown::{hash_map::DefaultHashBuilder, HashSet};
+use hashbrown::{hash_map::DefaultHashBuilder, HashMap};
 
-use crate::common::{Link, Node};
+use crate::common::IntrusiveList;
+
+/// The capacity used by [`FIFOSet::with_hasher`] when no explicit capacity
+/// is given.
+const DEFAULT_CAPACITY: usize = 16;
 
 /// A First-In-First-Out (FIFO) set.
 ///
@@ -16,12 +18,12 @@ use crate::common::{Link, Node};
 /// for implementing a cache with a fixed size to prevent it from growing indefinitely.
 ///
 /// It is implemented with a doubly linked list that keeps track of the oldest and newest
-/// entries and a hashset that maps keys to the linked list.
+/// entries and a hashmap that maps keys to the corresponding linked list index.
 ///
 /// # Example
 ///
 /// ```rust
-/// use fifo_set::FIFOSet;
+/// use hashmore::FIFOSet;
 ///
 /// let mut set = FIFOSet::with_capacity(3);
 ///
@@ -43,9 +45,8 @@ use crate::common::{Link, Node};
 /// ```
 #[derive(Debug)]
 pub struct FIFOSet<K, S = DefaultHashBuilder> {
-    set: HashSet<K, S>,
-    head: Link<K>,
-    tail: Link<K>,
+    map: HashMap<K, usize, S>,
+    list: IntrusiveList<K>,
     cap: NonZeroUsize,
 }
 
@@ -62,7 +63,46 @@ where
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         let cap = NonZeroUsize::new(capacity).expect("FIFOSet capacity must be non-zero");
-        Self { set: HashSet::with_capacity(capacity), head: None, tail: None, cap }
+        Self {
+            map: HashMap::with_capacity(capacity),
+            list: IntrusiveList::with_capacity(capacity),
+            cap,
+        }
+    }
+}
+
+impl<K, S> FIFOSet<K, S> {
+    /// Creates a new FIFO set which will use the given hash builder, with a
+    /// default capacity of 16 entries.
+    ///
+    /// Use [`with_capacity_and_hasher`](Self::with_capacity_and_hasher) to
+    /// choose an explicit capacity.
+    #[inline]
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(DEFAULT_CAPACITY, hasher)
+    }
+
+    /// Creates a new FIFO set with the given capacity which will use the
+    /// given hash builder.
+    /// The capacity must be greater than zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero.
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        let cap = NonZeroUsize::new(capacity).expect("FIFOSet capacity must be non-zero");
+        Self {
+            map: HashMap::with_capacity_and_hasher(capacity, hasher),
+            list: IntrusiveList::with_capacity(capacity),
+            cap,
+        }
+    }
+
+    /// Returns a reference to the set's [`BuildHasher`].
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
     }
 }
 
@@ -76,27 +116,16 @@ where
     /// - If the key is already in the set, it will not be inserted again.
     #[inline]
     pub fn insert(&mut self, key: K) {
-        if self.set.len() == self.cap.get() {
-            self.remove_first();
-        }
-
-        if self.set.contains(&key) {
+        if self.map.contains_key(&key) {
             return;
         }
 
-        let new_node = Node { key: key.clone(), next: None, prev: self.tail.clone() };
-        let new_node_ref = Rc::new(RefCell::new(new_node));
-
-        if let Some(tail) = self.tail.take() {
-            tail.borrow_mut().next = Some(new_node_ref.clone());
-        }
-        self.tail = Some(new_node_ref.clone());
-
-        if self.head.is_none() {
-            self.head = Some(new_node_ref.clone());
+        if self.map.len() == self.cap.get() {
+            self.remove_first();
         }
 
-        self.set.insert(key);
+        let index = self.list.push_back(key.clone());
+        self.map.insert(key, index);
     }
 
     /// Removes a key from the set.
@@ -104,42 +133,25 @@ where
     /// removed, `false` otherwise.
     #[inline]
     pub fn remove(&mut self, key: &K) -> bool {
-        if !self.set.remove(key) {
-            return false;
-        }
-
-        let mut current = self.head.clone();
-        while let Some(node) = current {
-            let next = node.borrow().next.clone();
-            if node.borrow().key == *key {
-                if let Some(prev) = node.borrow().prev.clone() {
-                    prev.borrow_mut().next.clone_from(&next)
-                } else {
-                    self.head.clone_from(&next)
-                }
-                if let Some(next) = next.clone() {
-                    next.borrow_mut().prev.clone_from(&node.borrow().prev);
-                } else {
-                    self.tail.clone_from(&node.borrow().prev);
-                }
-                return true;
+        match self.map.remove(key) {
+            Some(index) => {
+                self.list.unlink(index);
+                true
             }
-            current = next;
+            None => false,
         }
-
-        false
     }
 
     /// Returns the number of unique keys currently in the set.
     #[inline]
     pub fn len(&self) -> usize {
-        self.set.len()
+        self.map.len()
     }
 
     /// Returns `true` if the set is empty.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.set.is_empty()
+        self.map.is_empty()
     }
 
     /// Returns the capacity of the set.
@@ -150,9 +162,15 @@ where
 
     /// An iterator visiting all keys in insertion order.
     /// The keys are returned by reference.
+    ///
+    /// This walks the intrusive linked list from the oldest entry (`head`) to
+    /// the newest (`tail`), so the order always matches insertion order,
+    /// regardless of the underlying hashmap's iteration order. The iterator
+    /// is also [`DoubleEndedIterator`], so `.rev()` walks from `tail` to
+    /// `head`.
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &K> {
-        self.set.iter()
+    pub const fn iter(&self) -> Iter<'_, K, S> {
+        Iter { map: &self.map, list: &self.list, front: self.list.head, back: self.list.tail }
     }
 
     /// Checks if the set contains the given key.
@@ -160,20 +178,67 @@ where
     /// - The key is not removed from the set.
     #[inline]
     pub fn contains(&self, key: &K) -> bool {
-        self.set.contains(key)
+        self.map.contains_key(key)
     }
 
     fn remove_first(&mut self) {
-        if let Some(head) = self.head.take() {
-            if let Some(next) = head.borrow_mut().next.take() {
-                next.borrow_mut().prev = None;
-                self.head = Some(next);
-            } else {
-                self.tail.take();
-            }
-            let key = head.borrow().key.clone();
-            self.set.remove(&key);
+        if let Some(head) = self.list.head {
+            let key = self.list.key(head).clone();
+            self.list.unlink(head);
+            self.map.remove(&key);
+        }
+    }
+}
+
+/// An iterator over the keys of a [`FIFOSet`], in insertion order.
+///
+/// This struct is created by the [`iter`](FIFOSet::iter) method. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct Iter<'a, K, S> {
+    map: &'a HashMap<K, usize, S>,
+    list: &'a IntrusiveList<K>,
+    front: Option<usize>,
+    back: Option<usize>,
+}
+
+impl<'a, K, S> Iterator for Iter<'a, K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.front.take()?;
+        if self.back == Some(index) {
+            self.back = None;
+        } else {
+            self.front = self.list.node(index).next;
         }
+
+        let key = self.list.key(index).clone();
+        self.map.get_key_value(&key).map(|(k, _)| k)
+    }
+}
+
+impl<'a, K, S> DoubleEndedIterator for Iter<'a, K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.back.take()?;
+        if self.front == Some(index) {
+            self.front = None;
+        } else {
+            self.back = self.list.node(index).prev;
+        }
+
+        let key = self.list.key(index).clone();
+        self.map.get_key_value(&key).map(|(k, _)| k)
     }
 }
 
@@ -181,6 +246,37 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fifo_set_with_capacity_and_hasher() {
+        let mut set = FIFOSet::with_capacity_and_hasher(2, DefaultHashBuilder::default());
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        assert!(!set.contains(&1));
+        assert!(set.contains(&2));
+        assert!(set.contains(&3));
+    }
+
+    #[test]
+    fn test_fifo_set_reinsert_existing_key_at_capacity_is_a_no_op() {
+        let mut set = FIFOSet::with_capacity(3);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        // Re-inserting an existing, non-oldest key while full must not
+        // evict anything.
+        set.insert(2);
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&1));
+        assert!(set.contains(&2));
+        assert!(set.contains(&3));
+    }
+
     #[test]
     fn test_fifo_set() {
         let mut set = FIFOSet::with_capacity(3);
@@ -234,4 +330,30 @@ mod tests {
         assert!(!set.contains(&2));
         assert!(!set.contains(&3));
     }
+
+    #[test]
+    fn test_fifo_set_iter_order() {
+        let mut set = FIFOSet::with_capacity(3);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(set.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    }
+
+    #[test]
+    fn test_fifo_set_remove_then_insert_reuses_slab_slot() {
+        let mut set = FIFOSet::with_capacity(3);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        set.remove(&2);
+        set.insert(4);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![&1, &3, &4]);
+    }
 }
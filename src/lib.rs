@@ -3,6 +3,8 @@
 //! Available data structures:
 //! - FIFOMap
 //! - FIFOSet
+//! - LRUMap
+//! - LRUSet
 //! - More to come!
 
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
@@ -28,8 +30,22 @@
     unused_unsafe
 )]
 
+mod common;
+
 mod fifo_map;
-pub use fifo_map::FIFOMap;
+pub use fifo_map::{
+    Entry, FIFOMap, Iter as FIFOMapIter, Keys as FIFOMapKeys, OccupiedEntry, VacantEntry,
+    Values as FIFOMapValues,
+};
 
 mod fifo_set;
-pub use fifo_set::FIFOSet;
+pub use fifo_set::{FIFOSet, Iter as FIFOSetIter};
+
+mod lru_map;
+pub use lru_map::{LRUMap, Iter as LRUMapIter, Keys as LRUMapKeys, Values as LRUMapValues};
+
+mod lru_set;
+pub use lru_set::{LRUSet, Iter as LRUSetIter};
+
+#[cfg(feature = "serde")]
+mod serde_impl;
@@ -0,0 +1,408 @@
+use hashbrown::{hash_map::DefaultHashBuilder, HashMap};
+use std::{hash::Hash, num::NonZeroUsize};
+
+use crate::common::IntrusiveList;
+
+/// A Least-Recently-Used (LRU) map.
+///
+/// This hashmap has a fixed, pre-allocated capacity and will remove the least
+/// recently used entry when the capacity is reached and a new entry is
+/// inserted. Unlike [`FIFOMap`](crate::FIFOMap), a successful [`get`](Self::get),
+/// [`get_mut`](Self::get_mut) or [`contains_key`](Self::contains_key) on an
+/// existing key moves that key to the most-recently-used end, so it is
+/// useful for implementing a cache that keeps hot entries alive under
+/// repeated access.
+///
+/// It is implemented with a doubly linked list that keeps track of the least
+/// and most recently used entries and a hashmap that maps keys to values and
+/// the corresponding linked list index.
+///
+/// # Example
+///
+/// ```rust
+/// use hashmore::LRUMap;
+///
+/// let mut map = LRUMap::with_capacity(3);
+///
+/// map.insert("a", 1);
+/// map.insert("b", 2);
+/// map.insert("c", 3);
+///
+/// // "a" is now the most recently used entry
+/// assert_eq!(map.get(&"a"), Some(&1));
+///
+/// map.insert("d", 4);
+///
+/// // "b" was the least recently used entry, so it is removed
+/// assert_eq!(map.get(&"b"), None);
+/// assert_eq!(map.get(&"a"), Some(&1));
+/// ```
+#[derive(Debug)]
+pub struct LRUMap<K, V, S = DefaultHashBuilder> {
+    map: HashMap<K, (V, usize), S>,
+    list: IntrusiveList<K>,
+    cap: NonZeroUsize,
+}
+
+impl<K, V> LRUMap<K, V>
+where
+    K: Eq + Hash,
+{
+    /// Creates a new LRU map with the given capacity.
+    /// The capacity must be greater than zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let cap = NonZeroUsize::new(capacity).expect("LRUMap capacity must be non-zero");
+        Self {
+            map: HashMap::with_capacity(capacity),
+            list: IntrusiveList::with_capacity(capacity),
+            cap,
+        }
+    }
+}
+
+impl<K, V> LRUMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Inserts a new key-value pair into the map.
+    /// - If the map is at capacity, the least recently used entry will be removed.
+    /// - If the key is already in the map, the value will be updated and the
+    ///   key will be promoted to the most-recently-used end.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some((v, index)) = self.map.get_mut(&key) {
+            *v = value;
+            self.list.move_to_back(*index);
+            return;
+        }
+
+        if self.map.len() == self.cap.get() {
+            self.remove_first();
+        }
+
+        let index = self.list.push_back(key.clone());
+        self.map.insert(key, (value, index));
+    }
+
+    /// Removes a key-value pair from the map and returns the value.
+    /// If the key is not in the map, `None` is returned.
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.map.remove(key).map(|(v, index)| {
+            self.list.unlink(index);
+            v
+        })
+    }
+
+    /// Returns the number of key-value pairs currently in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the capacity of the map.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.cap.get()
+    }
+
+    /// An iterator visiting all keys, ordered from least to most recently used.
+    /// The keys are returned by reference.
+    #[inline]
+    pub const fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// An iterator visiting all values, ordered from least to most recently used.
+    /// The values are returned by reference.
+    #[inline]
+    pub const fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+
+    /// An iterator visiting all key-value pairs, ordered from least to most
+    /// recently used.
+    ///
+    /// This walks the intrusive linked list from `head` (least recently used)
+    /// to `tail` (most recently used) and does not itself touch the
+    /// recency ordering. The iterator is also [`DoubleEndedIterator`], so
+    /// `.rev()` walks from most to least recently used.
+    #[inline]
+    pub const fn iter(&self) -> Iter<'_, K, V> {
+        Iter { map: &self.map, list: &self.list, front: self.list.head, back: self.list.tail }
+    }
+
+    /// Returns a reference to the value corresponding to the key, promoting
+    /// the key to the most-recently-used end.
+    /// If the key is not in the map, `None` is returned.
+    #[inline]
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let index = self.map.get(key).map(|(_, index)| *index)?;
+        self.list.move_to_back(index);
+        self.map.get(key).map(|(v, _)| v)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key,
+    /// promoting the key to the most-recently-used end.
+    /// If the key is not in the map, `None` is returned.
+    #[inline]
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.map.get(key).map(|(_, index)| *index)?;
+        self.list.move_to_back(index);
+        self.map.get_mut(key).map(|(v, _)| v)
+    }
+
+    /// Checks if the map contains the given key, promoting it to the
+    /// most-recently-used end if it is present.
+    #[inline]
+    pub fn contains_key(&mut self, key: &K) -> bool {
+        let Some(index) = self.map.get(key).map(|(_, index)| *index) else {
+            return false;
+        };
+        self.list.move_to_back(index);
+        true
+    }
+
+    /// Removes the least recently used entry from the map.
+    /// If the map is empty, this is a no-op.
+    fn remove_first(&mut self) {
+        if let Some(head) = self.list.head {
+            let key = self.list.key(head).clone();
+            self.list.unlink(head);
+            self.map.remove(&key);
+        }
+    }
+}
+
+/// An iterator over the key-value pairs of an [`LRUMap`], ordered from least
+/// to most recently used.
+///
+/// This struct is created by the [`iter`](LRUMap::iter) method. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct Iter<'a, K, V> {
+    map: &'a HashMap<K, (V, usize)>,
+    list: &'a IntrusiveList<K>,
+    front: Option<usize>,
+    back: Option<usize>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.front.take()?;
+        if self.back == Some(index) {
+            self.back = None;
+        } else {
+            self.front = self.list.node(index).next;
+        }
+
+        let key = self.list.key(index).clone();
+        self.map.get_key_value(&key).map(|(k, (v, _))| (k, v))
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.back.take()?;
+        if self.front == Some(index) {
+            self.front = None;
+        } else {
+            self.back = self.list.node(index).prev;
+        }
+
+        let key = self.list.key(index).clone();
+        self.map.get_key_value(&key).map(|(k, (v, _))| (k, v))
+    }
+}
+
+/// An iterator visiting all keys of an [`LRUMap`], ordered from least to most
+/// recently used.
+///
+/// This struct is created by the [`keys`](LRUMap::keys) method. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct Keys<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(k, _)| k)
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(k, _)| k)
+    }
+}
+
+/// An iterator visiting all values of an [`LRUMap`], ordered from least to
+/// most recently used.
+///
+/// This struct is created by the [`values`](LRUMap::values) method. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct Values<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lru_map::LRUMap;
+
+    #[test]
+    fn test_lru_map_reach_cap() {
+        let mut map = LRUMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        map.insert("d", 4);
+
+        assert_eq!(map.get(&"a"), None);
+        assert_eq!(map.get(&"b"), Some(&2));
+        assert_eq!(map.get(&"c"), Some(&3));
+        assert_eq!(map.get(&"d"), Some(&4));
+    }
+
+    #[test]
+    fn test_lru_map_get_promotes_to_back() {
+        let mut map = LRUMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        // "a" is now the most recently used, "b" becomes the least recently used
+        assert_eq!(map.get(&"a"), Some(&1));
+
+        map.insert("d", 4);
+
+        assert_eq!(map.get(&"b"), None);
+        assert_eq!(map.get(&"a"), Some(&1));
+        assert_eq!(map.get(&"c"), Some(&3));
+        assert_eq!(map.get(&"d"), Some(&4));
+    }
+
+    #[test]
+    fn test_lru_map_contains_key_promotes_to_back() {
+        let mut map = LRUMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        assert!(map.contains_key(&"a"));
+
+        map.insert("d", 4);
+
+        assert!(!map.contains_key(&"b"));
+        assert!(map.contains_key(&"a"));
+        assert!(map.contains_key(&"c"));
+        assert!(map.contains_key(&"d"));
+    }
+
+    #[test]
+    fn test_lru_map_insert_existing_key_promotes_to_back() {
+        let mut map = LRUMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        map.insert("a", 10);
+
+        map.insert("d", 4);
+
+        assert_eq!(map.get(&"b"), None);
+        assert_eq!(map.get(&"a"), Some(&10));
+    }
+
+    #[test]
+    fn test_lru_map_iter_order() {
+        let mut map = LRUMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        map.get(&"a");
+
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"b", &"c", &"a"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lru_map_zero_capacity() {
+        LRUMap::<u64, u64>::with_capacity(0);
+    }
+
+    #[test]
+    fn test_lru_map_remove() {
+        let mut map = LRUMap::with_capacity(3);
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        assert_eq!(map.remove(&"a"), Some(1));
+        assert_eq!(map.remove(&"b"), Some(2));
+        assert_eq!(map.remove(&"c"), Some(3));
+        assert_eq!(map.remove(&"d"), None);
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    hash::{BuildHasher, Hash},
+    num::NonZeroUsize,
+};
+
+use hashbrown::{hash_map::DefaultHashBuilder, HashMap};
+
+use crate::common::IntrusiveList;
+
+/// A Least-Recently-Used (LRU) set.
+///
+/// This set has a fixed, pre-allocated capacity and will remove the least
+/// recently used entry when the capacity is reached and a new entry is
+/// inserted. Unlike [`FIFOSet`](crate::FIFOSet), a successful
+/// [`contains`](Self::contains) on an existing key moves that key to the
+/// most-recently-used end.
+///
+/// It is implemented with a doubly linked list that keeps track of the least
+/// and most recently used entries and a hashmap that maps keys to the
+/// corresponding linked list index.
+///
+/// # Example
+///
+/// ```rust
+/// use hashmore::LRUSet;
+///
+/// let mut set = LRUSet::with_capacity(3);
+///
+/// set.insert(1);
+/// set.insert(2);
+/// set.insert(3);
+///
+/// // 1 is now the most recently used entry
+/// assert!(set.contains(&1));
+///
+/// set.insert(4);
+///
+/// // 2 was the least recently used entry, so it is removed
+/// assert!(!set.contains(&2));
+/// assert!(set.contains(&1));
+/// ```
+#[derive(Debug)]
+pub struct LRUSet<K, S = DefaultHashBuilder> {
+    map: HashMap<K, usize, S>,
+    list: IntrusiveList<K>,
+    cap: NonZeroUsize,
+}
+
+impl<K> LRUSet<K>
+where
+    K: Eq + Hash,
+{
+    /// Creates a new LRU set with the given capacity.
+    /// The capacity must be greater than zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let cap = NonZeroUsize::new(capacity).expect("LRUSet capacity must be non-zero");
+        Self {
+            map: HashMap::with_capacity(capacity),
+            list: IntrusiveList::with_capacity(capacity),
+            cap,
+        }
+    }
+}
+
+impl<K, S> LRUSet<K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    /// Inserts a new key into the set.
+    /// - If the set is at capacity, the least recently used entry will be removed.
+    /// - If the key is already in the set, it is promoted to the
+    ///   most-recently-used end instead of being inserted again.
+    #[inline]
+    pub fn insert(&mut self, key: K) {
+        if let Some(index) = self.map.get(&key) {
+            self.list.move_to_back(*index);
+            return;
+        }
+
+        if self.map.len() == self.cap.get() {
+            self.remove_first();
+        }
+
+        let index = self.list.push_back(key.clone());
+        self.map.insert(key, index);
+    }
+
+    /// Removes a key from the set.
+    /// Returns `true` if the key was in the set and was
+    /// removed, `false` otherwise.
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> bool {
+        match self.map.remove(key) {
+            Some(index) => {
+                self.list.unlink(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the number of unique keys currently in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the capacity of the set.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.cap.get()
+    }
+
+    /// An iterator visiting all keys, ordered from least to most recently used.
+    /// The keys are returned by reference.
+    ///
+    /// This walks the intrusive linked list from `head` (least recently
+    /// used) to `tail` (most recently used) and does not itself touch the
+    /// recency ordering. The iterator is also [`DoubleEndedIterator`], so
+    /// `.rev()` walks from most to least recently used.
+    #[inline]
+    pub const fn iter(&self) -> Iter<'_, K, S> {
+        Iter { map: &self.map, list: &self.list, front: self.list.head, back: self.list.tail }
+    }
+
+    /// Checks if the set contains the given key, promoting it to the
+    /// most-recently-used end if it is present.
+    #[inline]
+    pub fn contains(&mut self, key: &K) -> bool {
+        let Some(index) = self.map.get(key).copied() else {
+            return false;
+        };
+        self.list.move_to_back(index);
+        true
+    }
+
+    fn remove_first(&mut self) {
+        if let Some(head) = self.list.head {
+            let key = self.list.key(head).clone();
+            self.list.unlink(head);
+            self.map.remove(&key);
+        }
+    }
+}
+
+/// An iterator over the keys of an [`LRUSet`], ordered from least to most
+/// recently used.
+///
+/// This struct is created by the [`iter`](LRUSet::iter) method. See its
+/// documentation for more.
+#[derive(Debug)]
+pub struct Iter<'a, K, S> {
+    map: &'a HashMap<K, usize, S>,
+    list: &'a IntrusiveList<K>,
+    front: Option<usize>,
+    back: Option<usize>,
+}
+
+impl<'a, K, S> Iterator for Iter<'a, K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.front.take()?;
+        if self.back == Some(index) {
+            self.back = None;
+        } else {
+            self.front = self.list.node(index).next;
+        }
+
+        let key = self.list.key(index).clone();
+        self.map.get_key_value(&key).map(|(k, _)| k)
+    }
+}
+
+impl<'a, K, S> DoubleEndedIterator for Iter<'a, K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.back.take()?;
+        if self.front == Some(index) {
+            self.front = None;
+        } else {
+            self.back = self.list.node(index).prev;
+        }
+
+        let key = self.list.key(index).clone();
+        self.map.get_key_value(&key).map(|(k, _)| k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_set_reach_cap() {
+        let mut set = LRUSet::with_capacity(3);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        set.insert(4);
+
+        assert!(!set.contains(&1));
+        assert!(set.contains(&2));
+        assert!(set.contains(&3));
+        assert!(set.contains(&4));
+    }
+
+    #[test]
+    fn test_lru_set_contains_promotes_to_back() {
+        let mut set = LRUSet::with_capacity(3);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        // 1 is now the most recently used
+        assert!(set.contains(&1));
+
+        set.insert(4);
+
+        assert!(!set.contains(&2));
+        assert!(set.contains(&1));
+        assert!(set.contains(&3));
+        assert!(set.contains(&4));
+    }
+
+    #[test]
+    fn test_lru_set_insert_existing_key_promotes_to_back() {
+        let mut set = LRUSet::with_capacity(3);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        set.insert(1);
+        set.insert(4);
+
+        assert!(!set.contains(&2));
+        assert!(set.contains(&1));
+    }
+
+    #[test]
+    fn test_lru_set_remove() {
+        let mut set = LRUSet::with_capacity(3);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        assert!(set.remove(&2));
+        assert!(!set.contains(&2));
+        assert!(set.contains(&1));
+        assert!(set.contains(&3));
+    }
+}
@@ -0,0 +1,127 @@
+//! [`serde`] support for [`FIFOMap`] and [`FIFOSet`], gated behind the
+//! `serde` feature.
+//!
+//! Both types serialize as a `(capacity, entries)` tuple, with `entries`
+//! listing the keys (and, for `FIFOMap`, values) in FIFO order by walking
+//! the intrusive linked list from `head` to `tail`. Deserializing re-inserts
+//! the entries in that same order into a map or set created with the
+//! serialized capacity, so the reconstructed structure has an identical
+//! eviction sequence, not merely the same contents.
+
+use std::hash::{BuildHasher, Hash};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{FIFOMap, FIFOSet};
+
+impl<K, V, S> Serialize for FIFOMap<K, V, S>
+where
+    K: Serialize + Eq + Hash + Clone,
+    V: Serialize,
+    S: BuildHasher,
+{
+    #[inline]
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        (self.capacity(), self.iter().collect::<Vec<_>>()).serialize(serializer)
+    }
+}
+
+impl<'de, K, V, S> Deserialize<'de> for FIFOMap<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash + Clone,
+    V: Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (capacity, entries): (usize, Vec<(K, V)>) = Deserialize::deserialize(deserializer)?;
+        let mut map = Self::with_capacity_and_hasher(capacity, S::default());
+        for (key, value) in entries {
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+impl<K, S> Serialize for FIFOSet<K, S>
+where
+    K: Serialize + Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    #[inline]
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        (self.capacity(), self.iter().collect::<Vec<_>>()).serialize(serializer)
+    }
+}
+
+impl<'de, K, S> Deserialize<'de> for FIFOSet<K, S>
+where
+    K: Deserialize<'de> + Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (capacity, entries): (usize, Vec<K>) = Deserialize::deserialize(deserializer)?;
+        let mut set = Self::with_capacity_and_hasher(capacity, S::default());
+        for key in entries {
+            set.insert(key);
+        }
+        Ok(set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hashbrown::hash_map::DefaultHashBuilder;
+
+    use crate::{FIFOMap, FIFOSet};
+
+    #[test]
+    fn test_fifo_map_roundtrip_preserves_order_and_capacity() {
+        let mut map: FIFOMap<&str, i32> = FIFOMap::with_capacity(3);
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        let json = serde_json::to_string(&map).unwrap();
+        let mut restored: FIFOMap<&str, i32, DefaultHashBuilder> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.capacity(), 3);
+        assert_eq!(restored.keys().collect::<Vec<_>>(), vec![&"a", &"b", &"c"]);
+
+        restored.insert("d", 4);
+        assert_eq!(restored.get(&"a"), None);
+        assert_eq!(restored.get(&"d"), Some(&4));
+    }
+
+    #[test]
+    fn test_fifo_set_roundtrip_preserves_order_and_capacity() {
+        let mut set: FIFOSet<i32> = FIFOSet::with_capacity(3);
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        let json = serde_json::to_string(&set).unwrap();
+        let mut restored: FIFOSet<i32, DefaultHashBuilder> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.capacity(), 3);
+        assert_eq!(restored.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+
+        restored.insert(4);
+        assert!(!restored.contains(&1));
+        assert!(restored.contains(&4));
+    }
+}